@@ -0,0 +1,198 @@
+// Copyright 2019-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::path;
+
+use anyhow::Context;
+
+use crate::{
+    package_manager::PackageManager,
+    template::{
+        EmbeddedSource, FilesystemSource, GenerationConfig, RemoteSource, Template, TemplateSource,
+    },
+};
+
+/// Flags controlling where [`Template::render_from_source`] reads its fragments from: the
+/// embedded templates by default, or a user-supplied `--template-dir`/`--template-git`.
+#[derive(Debug, Default)]
+pub struct TemplateSourceArgs {
+    pub template_dir: Option<path::PathBuf>,
+    pub template_git: Option<String>,
+}
+
+impl TemplateSourceArgs {
+    pub fn resolve(&self) -> anyhow::Result<Box<dyn TemplateSource>> {
+        match (&self.template_dir, &self.template_git) {
+            (Some(dir), None) => Ok(Box::new(FilesystemSource::new(dir.clone()))),
+            (None, Some(url)) => Ok(Box::new(RemoteSource::from_git(url)?)),
+            (None, None) => Ok(Box::new(EmbeddedSource)),
+            (Some(_), Some(_)) => {
+                anyhow::bail!("--template-dir and --template-git cannot be used together")
+            }
+        }
+    }
+}
+
+/// Parsed `create-tauri-app` command-line flags.
+#[derive(Debug, Default)]
+pub struct Args {
+    pub package_name: Option<String>,
+    pub template: Option<Template>,
+    pub manager: Option<PackageManager>,
+    pub alpha: bool,
+    pub mobile: bool,
+    pub directory: Option<path::PathBuf>,
+    pub source: TemplateSourceArgs,
+    pub from_config: Option<path::PathBuf>,
+}
+
+impl Args {
+    /// Parses `create-tauri-app` CLI flags in `--flag value` / `--flag=value` style, with a
+    /// single bare positional argument taken as the package name.
+    pub fn parse(raw: impl IntoIterator<Item = String>) -> anyhow::Result<Self> {
+        let mut args = Args::default();
+        let mut iter = raw.into_iter();
+
+        while let Some(arg) = iter.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+
+            let mut value = |flag: &str| -> anyhow::Result<String> {
+                inline_value
+                    .clone()
+                    .or_else(|| iter.next())
+                    .with_context(|| format!("{flag} expects a value"))
+            };
+
+            match flag.as_str() {
+                "--template" => {
+                    args.template = Some(value(&flag)?.parse().map_err(|e| anyhow::anyhow!(e))?)
+                }
+                "--manager" => {
+                    args.manager = Some(value(&flag)?.parse().map_err(|e| anyhow::anyhow!(e))?)
+                }
+                "--alpha" => args.alpha = true,
+                "--mobile" => args.mobile = true,
+                "--directory" => args.directory = Some(path::PathBuf::from(value(&flag)?)),
+                "--template-dir" => {
+                    args.source.template_dir = Some(path::PathBuf::from(value(&flag)?))
+                }
+                "--template-git" => args.source.template_git = Some(value(&flag)?),
+                "--from-config" => args.from_config = Some(path::PathBuf::from(value(&flag)?)),
+                _ if !flag.starts_with("--") => args.package_name = Some(flag),
+                other => anyhow::bail!("unknown flag: {other}"),
+            }
+        }
+
+        if args.from_config.is_some()
+            && (args.source.template_dir.is_some() || args.source.template_git.is_some())
+        {
+            anyhow::bail!("--from-config cannot be combined with --template-dir/--template-git");
+        }
+
+        Ok(args)
+    }
+
+    /// Scaffolds a project per these flags: regenerates from `--from-config` if given (replaying
+    /// whichever `TemplateSource` produced the original project), otherwise renders `self.template`
+    /// from the resolved `--template-dir`/`--template-git`/embedded source.
+    pub fn run(&self) -> anyhow::Result<()> {
+        let target_dir = self
+            .directory
+            .clone()
+            .unwrap_or_else(|| path::PathBuf::from("."));
+
+        if let Some(config_path) = &self.from_config {
+            return GenerationConfig::generate(config_path, &target_dir);
+        }
+
+        let template = self.template.unwrap_or_default();
+        let pkg_manager = match self.manager {
+            Some(manager) => manager,
+            None => *template
+                .possible_package_managers()
+                .first()
+                .with_context(|| "template has no possible package managers")?,
+        };
+        let package_name = self
+            .package_name
+            .as_deref()
+            .with_context(|| "missing package name")?;
+        let source = self.source.resolve()?;
+
+        template.render_from_source(
+            source.as_ref(),
+            &target_dir,
+            pkg_manager,
+            package_name,
+            self.alpha,
+            self.mobile,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_template_source_flags() {
+        let args = Args::parse(
+            [
+                "cta-app",
+                "--template",
+                "vue-ts",
+                "--template-git",
+                "https://example.com/t.git",
+            ]
+            .into_iter()
+            .map(String::from),
+        )
+        .unwrap();
+
+        assert_eq!(args.package_name.as_deref(), Some("cta-app"));
+        assert_eq!(args.template, Some(Template::VueTs));
+        assert_eq!(
+            args.source.template_git.as_deref(),
+            Some("https://example.com/t.git")
+        );
+    }
+
+    #[test]
+    fn rejects_conflicting_template_sources() {
+        let args = Args::parse(
+            [
+                "--template-dir",
+                "./my-templates",
+                "--template-git",
+                "https://example.com/t.git",
+            ]
+            .into_iter()
+            .map(String::from),
+        )
+        .unwrap();
+
+        let err = args.source.resolve().unwrap_err();
+        assert!(err.to_string().contains("cannot be used together"));
+    }
+
+    #[test]
+    fn rejects_from_config_with_a_template_source() {
+        let err = Args::parse(
+            [
+                "--from-config",
+                "create-tauri-app.json",
+                "--template-dir",
+                "./my-templates",
+            ]
+            .into_iter()
+            .map(String::from),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("--from-config"));
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2019-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+
+/// Parsed contents of a fragment's `_cta_manifest_` file: the `beforeDevCommand`,
+/// `beforeBuildCommand`, `devPath` and `distDir` values substituted into `tauri.conf.json`, the
+/// extra `[files]` to copy into the generated project, and the `[dependencies]`/`[versions]`
+/// map of pinned dependency versions resolved through `~dep:<name>~` tokens.
+#[derive(Debug, Clone, Default)]
+pub struct Manifest {
+    before_dev_command: String,
+    before_build_command: String,
+    dev_path: String,
+    dist_dir: String,
+    pub files: Vec<(String, String)>,
+    versions: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Root,
+    Files,
+    Versions,
+}
+
+impl Manifest {
+    pub fn parse(content: &str, _mobile: bool) -> anyhow::Result<Self> {
+        let mut manifest = Manifest::default();
+        let mut section = Section::Root;
+
+        for line in content.lines() {
+            // strip trailing comments
+            let line = match line.find('#') {
+                Some(idx) => &line[..idx],
+                None => line,
+            };
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.starts_with('[') && line.ends_with(']') {
+                section = match &line[1..line.len() - 1] {
+                    "files" => Section::Files,
+                    "dependencies" | "versions" => Section::Versions,
+                    other => anyhow::bail!("unknown manifest section [{other}]"),
+                };
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .with_context(|| format!("invalid manifest line: {line}"))?;
+            let (key, value) = (key.trim().to_string(), strip_quotes(value.trim()).to_string());
+
+            match section {
+                Section::Root => match key.as_str() {
+                    "beforeDevCommand" => manifest.before_dev_command = value,
+                    "beforeBuildCommand" => manifest.before_build_command = value,
+                    "devPath" => manifest.dev_path = value,
+                    "distDir" => manifest.dist_dir = value,
+                    other => anyhow::bail!("unknown manifest key: {other}"),
+                },
+                Section::Files => manifest.files.push((key, value)),
+                Section::Versions => {
+                    manifest.versions.insert(key, value);
+                }
+            }
+        }
+
+        Ok(manifest)
+    }
+
+    pub fn replace_vars(&self, content: &str) -> String {
+        content
+            .replace("~fragment_before_dev_command~", &self.before_dev_command)
+            .replace(
+                "~fragment_before_build_command~",
+                &self.before_build_command,
+            )
+            .replace("~fragment_dev_path~", &self.dev_path)
+            .replace("~fragment_dist_dir~", &self.dist_dir)
+    }
+
+    /// Looks up the version pinned for `name` in the fragment manifest's `[dependencies]`/
+    /// `[versions]` section. Used to resolve `~dep:<name>~` tokens into a single authoritative
+    /// version shared by every rendered manifest file.
+    pub fn dependency_version(&self, name: &str) -> Option<&str> {
+        self.versions.get(name).map(String::as_str)
+    }
+}
+
+/// Strips a single matching pair of surrounding `"`/`'` quotes from `s`, if present. Lets manifest
+/// values be written either way, e.g. `tauri = "2"` or `tauri = 2`, without embedding literal
+/// quote characters in substituted output.
+fn strip_quotes(s: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = s.strip_prefix(quote).and_then(|s| s.strip_suffix(quote)) {
+            return inner;
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_quoted_and_unquoted_dependency_versions() {
+        let manifest = Manifest::parse(
+            r#"
+        [dependencies]
+        tauri = "2"
+        @tauri-apps/api = "^2"
+        serde = 1
+    "#,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.dependency_version("tauri"), Some("2"));
+        assert_eq!(manifest.dependency_version("@tauri-apps/api"), Some("^2"));
+        assert_eq!(manifest.dependency_version("serde"), Some("1"));
+        assert_eq!(manifest.dependency_version("missing"), None);
+    }
+}
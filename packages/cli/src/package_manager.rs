@@ -0,0 +1,96 @@
+// Copyright 2019-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{colors::*, template::suggestion_suffix};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PackageManager {
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+    Cargo,
+}
+
+impl PackageManager {
+    pub const ALL: &'static [PackageManager] = &[
+        PackageManager::Npm,
+        PackageManager::Pnpm,
+        PackageManager::Yarn,
+        PackageManager::Bun,
+        PackageManager::Cargo,
+    ];
+
+    pub const NODE: &'static [PackageManager] = &[
+        PackageManager::Npm,
+        PackageManager::Pnpm,
+        PackageManager::Yarn,
+        PackageManager::Bun,
+    ];
+
+    pub const fn run_cmd(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm run",
+            PackageManager::Pnpm => "pnpm",
+            PackageManager::Yarn => "yarn",
+            PackageManager::Bun => "bun run",
+            PackageManager::Cargo => "cargo",
+        }
+    }
+}
+
+impl Display for PackageManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackageManager::Npm => write!(f, "npm"),
+            PackageManager::Pnpm => write!(f, "pnpm"),
+            PackageManager::Yarn => write!(f, "yarn"),
+            PackageManager::Bun => write!(f, "bun"),
+            PackageManager::Cargo => write!(f, "cargo"),
+        }
+    }
+}
+
+impl FromStr for PackageManager {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "npm" => Ok(PackageManager::Npm),
+            "pnpm" => Ok(PackageManager::Pnpm),
+            "yarn" => Ok(PackageManager::Yarn),
+            "bun" => Ok(PackageManager::Bun),
+            "cargo" => Ok(PackageManager::Cargo),
+            _ => Err(format!(
+                "{YELLOW}{s}{RESET} is not a valid package manager.{} Valid package managers are [{}]",
+                suggestion_suffix(s, PackageManager::ALL),
+                PackageManager::ALL
+                    .iter()
+                    .map(|e| format!("{GREEN}{e}{RESET}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn suggests_closest_package_manager_on_typo() {
+        let err = PackageManager::from_str("pnmp").unwrap_err();
+        assert!(err.contains(&format!("Did you mean `{GREEN}pnpm{RESET}`?")), "{err}");
+
+        let err = PackageManager::from_str("yarnn").unwrap_err();
+        assert!(err.contains(&format!("Did you mean `{GREEN}yarn{RESET}`?")), "{err}");
+
+        // too far from anything to be a useful suggestion
+        let err = PackageManager::from_str("xxxxxxxxxxxx").unwrap_err();
+        assert!(!err.contains("Did you mean"), "{err}");
+    }
+}
@@ -2,7 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use std::{fmt::Display, fs, io::Write, path, str::FromStr};
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    fs,
+    hash::{Hash, Hasher},
+    io::Write,
+    path,
+    str::FromStr,
+};
 
 use anyhow::Context;
 use rust_embed::RustEmbed;
@@ -14,6 +22,204 @@ use crate::{colors::*, manifest::Manifest, package_manager::PackageManager};
 #[allow(clippy::upper_case_acronyms)]
 struct FRAGMENTS;
 
+/// Abstracts what [`Template::render`] needs from wherever fragments live (reading a file with
+/// `get`, listing files with `iter`, and naming the source with `kind`), so
+/// project skeletons don't have to come from the embedded `fragments/` folder. Implement this to
+/// plug in a custom fragment source (e.g. a directory on disk, or a cloned git repository).
+pub trait TemplateSource {
+    /// Returns the raw bytes of the fragment file at `path`, if it exists.
+    fn get(&self, path: &str) -> Option<Cow<'_, [u8]>>;
+    /// Iterates over every fragment path available in this source.
+    fn iter(&self) -> Box<dyn Iterator<Item = String> + '_>;
+    /// Describes where this source's fragments came from, so [`GenerationConfig`] can record it
+    /// and rebuild an equivalent source later for `--from-config`.
+    fn kind(&self) -> TemplateSourceKind;
+}
+
+/// Where a [`TemplateSource`]'s fragments came from. Recorded in [`GenerationConfig`] so
+/// [`GenerationConfig::generate`] can rebuild the same kind of source instead of always falling
+/// back to the embedded templates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateSourceKind {
+    Embedded,
+    Directory(path::PathBuf),
+    Git(String),
+    Tarball(String),
+}
+
+/// The default [`TemplateSource`], backed by the fragments embedded into the binary at compile
+/// time via [`FRAGMENTS`].
+pub struct EmbeddedSource;
+
+impl TemplateSource for EmbeddedSource {
+    fn get(&self, path: &str) -> Option<Cow<'_, [u8]>> {
+        FRAGMENTS::get(path).map(|f| f.data)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = String> + '_> {
+        Box::new(FRAGMENTS::iter().map(|e| e.to_string()))
+    }
+
+    fn kind(&self) -> TemplateSourceKind {
+        TemplateSourceKind::Embedded
+    }
+}
+
+/// A [`TemplateSource`] backed by a plain directory on disk, laid out exactly like the embedded
+/// `fragments/` folder (`_base_`, `fragment-<template>`, `_assets_`, ...). Used for
+/// `--template-dir` as well as the cache directories [`RemoteSource`] fetches into.
+pub struct FilesystemSource {
+    root: path::PathBuf,
+}
+
+impl FilesystemSource {
+    pub fn new(root: impl Into<path::PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl TemplateSource for FilesystemSource {
+    fn get(&self, path: &str) -> Option<Cow<'_, [u8]>> {
+        fs::read(self.root.join(path)).ok().map(Cow::Owned)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = String> + '_> {
+        Box::new(
+            walkdir::WalkDir::new(&self.root)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+                .filter_map(|e| {
+                    e.path()
+                        .strip_prefix(&self.root)
+                        .ok()
+                        .map(|p| p.to_string_lossy().replace('\\', "/"))
+                }),
+        )
+    }
+
+    fn kind(&self) -> TemplateSourceKind {
+        TemplateSourceKind::Directory(self.root.clone())
+    }
+}
+
+/// A [`TemplateSource`] fetched from a remote git repository or tarball into a local cache
+/// directory, then served like a [`FilesystemSource`]. Used for `--template-git`.
+pub struct RemoteSource {
+    inner: FilesystemSource,
+    kind: TemplateSourceKind,
+}
+
+impl RemoteSource {
+    /// Shallow-clones the git repository at `url` into the user's cache dir (reusing it on
+    /// subsequent runs) and serves fragments from the checkout.
+    pub fn from_git(url: &str) -> anyhow::Result<Self> {
+        let cache_dir = Self::cache_dir_for(url)?;
+        if !cache_dir.exists() {
+            let tmp_dir = Self::fresh_tmp_dir(&cache_dir)?;
+
+            let status = std::process::Command::new("git")
+                .args(["clone", "--depth", "1", url])
+                .arg(&tmp_dir)
+                .status()
+                .with_context(|| format!("failed to run `git clone {url}`"));
+
+            match status {
+                Ok(status) if status.success() => {}
+                Ok(status) => {
+                    let _ = fs::remove_dir_all(&tmp_dir);
+                    anyhow::bail!("`git clone {url}` exited with {status}");
+                }
+                Err(err) => {
+                    let _ = fs::remove_dir_all(&tmp_dir);
+                    return Err(err);
+                }
+            }
+
+            // only make the cache dir visible to the `!cache_dir.exists()` guard above once the
+            // clone actually succeeded, so a failed attempt doesn't poison it for future runs
+            fs::rename(&tmp_dir, &cache_dir)?;
+        }
+        Ok(Self {
+            inner: FilesystemSource::new(cache_dir),
+            kind: TemplateSourceKind::Git(url.to_string()),
+        })
+    }
+
+    /// Downloads the tarball at `url` and extracts it into the user's cache dir (reusing it on
+    /// subsequent runs), then serves fragments from the extracted contents.
+    pub fn from_tarball(url: &str) -> anyhow::Result<Self> {
+        let cache_dir = Self::cache_dir_for(url)?;
+        if !cache_dir.exists() {
+            let tmp_dir = Self::fresh_tmp_dir(&cache_dir)?;
+
+            let result = (|| -> anyhow::Result<()> {
+                let response = ureq::get(url)
+                    .call()
+                    .with_context(|| format!("failed to download {url}"))?;
+                let gz = flate2::read::GzDecoder::new(response.into_reader());
+                tar::Archive::new(gz)
+                    .unpack(&tmp_dir)
+                    .with_context(|| format!("failed to extract tarball from {url}"))
+            })();
+
+            if let Err(err) = result {
+                let _ = fs::remove_dir_all(&tmp_dir);
+                return Err(err);
+            }
+
+            // only make the cache dir visible to the `!cache_dir.exists()` guard above once the
+            // download and extraction actually succeeded, so a failed attempt doesn't poison it
+            // for future runs
+            fs::rename(&tmp_dir, &cache_dir)?;
+        }
+        Ok(Self {
+            inner: FilesystemSource::new(cache_dir),
+            kind: TemplateSourceKind::Tarball(url.to_string()),
+        })
+    }
+
+    /// Creates (and, if left over from a previously-failed fetch, clears) a scratch directory
+    /// next to `cache_dir` that fetches are written into before being renamed into place.
+    fn fresh_tmp_dir(cache_dir: &path::Path) -> anyhow::Result<path::PathBuf> {
+        let parent = cache_dir
+            .parent()
+            .with_context(|| "template cache directory has no parent")?;
+        fs::create_dir_all(parent)?;
+
+        let tmp_dir = cache_dir.with_extension("tmp");
+        if tmp_dir.exists() {
+            fs::remove_dir_all(&tmp_dir)?;
+        }
+        fs::create_dir_all(&tmp_dir)?;
+        Ok(tmp_dir)
+    }
+
+    fn cache_dir_for(url: &str) -> anyhow::Result<path::PathBuf> {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        url.hash(&mut hasher);
+        Ok(dirs::cache_dir()
+            .with_context(|| "could not determine the user's cache directory")?
+            .join("create-tauri-app")
+            .join("templates")
+            .join(format!("{:x}", hasher.finish())))
+    }
+}
+
+impl TemplateSource for RemoteSource {
+    fn get(&self, path: &str) -> Option<Cow<'_, [u8]>> {
+        self.inner.get(path)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = String> + '_> {
+        self.inner.iter()
+    }
+
+    fn kind(&self) -> TemplateSourceKind {
+        self.kind.clone()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Template {
@@ -103,7 +309,8 @@ impl FromStr for Template {
             "preact" => Ok(Template::Preact),
             "preact-ts" => Ok(Template::PreactTs),
             _ => Err(format!(
-                "{YELLOW}{s}{RESET} is not a valid template. Valid templates are [{}]",
+                "{YELLOW}{s}{RESET} is not a valid template.{} Valid templates are [{}]",
+                suggestion_suffix(s, Template::ALL),
                 Template::ALL
                     .iter()
                     .map(|e| format!("{GREEN}{e}{RESET}"))
@@ -114,6 +321,47 @@ impl FromStr for Template {
     }
 }
 
+/// Returns a levenshtein edit distance between `a` and `b`, comparing them byte-wise.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, a_byte) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, b_byte) in b.iter().enumerate() {
+            let cost = usize::from(a_byte != b_byte);
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Finds the closest match to `input` among `candidates` and, if it's close enough to plausibly
+/// be a typo, formats it as a " Did you mean `<candidate>`?" suggestion. Returns an empty string
+/// when nothing is close enough, so unrelated garbage still falls through to the full list.
+///
+/// Shared across `Template`, `Flavor` and `PackageManager` parsing.
+pub(crate) fn suggestion_suffix<T: Display>(input: &str, candidates: &[T]) -> String {
+    let closest = candidates
+        .iter()
+        .map(|c| (c, levenshtein_distance(input, &c.to_string())))
+        .min_by_key(|(_, distance)| *distance);
+
+    // a tighter bound like `len/3` rejects real typos such as `tuari-ts` -> `vue-ts` (distance 4
+    // over length 8), so fall back to half the input length.
+    match closest {
+        Some((candidate, distance)) if distance <= (input.len() / 2).max(2) => {
+            format!(" Did you mean `{GREEN}{candidate}{RESET}`?")
+        }
+        _ => String::new(),
+    }
+}
+
 impl<'a> Template {
     pub const ALL: &'a [Template] = &[
         Template::Vanilla,
@@ -226,9 +474,81 @@ impl<'a> Template {
         alpha: bool,
         mobile: bool,
     ) -> anyhow::Result<()> {
-        let manifest_bytes = FRAGMENTS::get(&format!("fragment-{self}/_cta_manifest_"))
-            .with_context(|| "Failed to get manifest bytes")?
-            .data;
+        self.render_from_source(
+            &EmbeddedSource,
+            target_dir,
+            pkg_manager,
+            package_name,
+            alpha,
+            mobile,
+        )
+    }
+
+    /// Resolves a conditional file's `"%(<flags>)%<file_name>"` name against the current render
+    /// settings, returning the plain `file_name` to write it under or `None` to skip it. Flags are
+    /// package managers, `stable`/`alpha`/`mobile`, and target OSes (`windows`/`macos`/`linux`);
+    /// package-manager and OS flags are OR-combined, all categories must agree for the file to be
+    /// written. `os` is threaded through instead of read from `std::env::consts::OS` so this stays
+    /// unit-testable.
+    fn resolve_conditional_file_name<'a>(
+        name: &'a str,
+        pkg_manager: PackageManager,
+        alpha: bool,
+        mobile: bool,
+        os: &str,
+    ) -> Option<&'a str> {
+        let mut s = name.strip_prefix("%(").unwrap().split(")%");
+        let (mut flags, name) = (
+            s.next()
+                .unwrap()
+                .split('-')
+                .filter(|f| !f.is_empty())
+                .collect::<Vec<_>>(),
+            s.next().unwrap(),
+        );
+
+        let for_stable = flags.contains(&"stable");
+        let for_alpha = flags.contains(&"alpha");
+        let for_mobile = flags.contains(&"mobile");
+
+        const OSES: &[&str] = &["windows", "macos", "linux"];
+        let for_oses = flags
+            .iter()
+            .copied()
+            .filter(|f| OSES.contains(f))
+            .collect::<Vec<_>>();
+
+        // remove these flags to only keep package managers flags
+        flags.retain(|e| !["stable", "alpha", "mobile"].contains(e) && !OSES.contains(e));
+
+        if ((for_stable && !alpha)
+            || (for_alpha && alpha && !mobile)
+            || (for_mobile && alpha && mobile)
+            || (!for_stable && !for_alpha && !for_mobile))
+            && (flags.contains(&pkg_manager.to_string().as_str()) || flags.is_empty())
+            && (for_oses.is_empty() || for_oses.contains(&os))
+        {
+            Some(name)
+        } else {
+            None
+        }
+    }
+
+    /// Same as [`Template::render`], but reads fragments from an arbitrary [`TemplateSource`]
+    /// instead of always going through the embedded assets. This is what backs `--template-dir`
+    /// and `--template-git`.
+    pub fn render_from_source(
+        &self,
+        source: &dyn TemplateSource,
+        target_dir: &path::Path,
+        pkg_manager: PackageManager,
+        package_name: &str,
+        alpha: bool,
+        mobile: bool,
+    ) -> anyhow::Result<()> {
+        let manifest_bytes = source
+            .get(&format!("fragment-{self}/_cta_manifest_"))
+            .with_context(|| "Failed to get manifest bytes")?;
         let manifest_str = String::from_utf8(manifest_bytes.to_vec())?;
         let manifest = Manifest::parse(&manifest_str, mobile)?;
 
@@ -255,38 +575,27 @@ impl<'a> Template {
                 // conditional files:
                 // are files that start with a special syntax
                 //          "%(<list of flags separated by `-`>%)<file_name>"
-                // flags are supported package managers, stable, alpha and mobile.
-                // example: "%(pnpm-npm-yarn-stable-alpha)%package.json"
+                // flags are supported package managers, stable, alpha, mobile and target OSes
+                // (windows, macos, linux). OS flags are OR-combined like package managers, and
+                // matched against the host running generation.
+                // example: "%(pnpm-npm-yarn-stable-alpha-windows-macos)%package.json"
                 name if name.starts_with("%(") && name[1..].contains(")%") => {
-                    let mut s = name.strip_prefix("%(").unwrap().split(")%");
-                    let (mut flags, name) = (
-                        s.next().unwrap().split('-').collect::<Vec<_>>(),
-                        s.next().unwrap(),
-                    );
-
-                    let for_stable = flags.contains(&"stable");
-                    let for_alpha = flags.contains(&"alpha");
-                    let for_mobile = flags.contains(&"mobile");
-
-                    // remove these flags to only keep package managers flags
-                    flags.retain(|e| !["stable", "alpha", "mobile"].contains(e));
-
-                    if ((for_stable && !alpha)
-                        || (for_alpha && alpha && !mobile)
-                        || (for_mobile && alpha && mobile)
-                        || (!for_stable && !for_alpha && !for_mobile))
-                        && (flags.contains(&pkg_manager.to_string().as_str()) || flags.is_empty())
-                    {
-                        name
-                    } else {
+                    match Self::resolve_conditional_file_name(
+                        name,
+                        pkg_manager,
+                        alpha,
+                        mobile,
+                        std::env::consts::OS,
+                    ) {
+                        Some(name) => name,
                         // skip writing this file
-                        return Ok(());
+                        None => return Ok(()),
                     }
                 }
                 _ => &file_name,
             };
 
-            let mut data = FRAGMENTS::get(file).unwrap().data.to_vec();
+            let mut data = source.get(file).unwrap().to_vec();
 
             // Only modify specific set of files
             if [
@@ -321,7 +630,7 @@ impl<'a> Template {
             Ok(())
         };
 
-        for file in FRAGMENTS::iter().filter(|e| {
+        for file in source.iter().filter(|e| {
             path::PathBuf::from(e.to_string())
                 .components()
                 .next()
@@ -333,7 +642,7 @@ impl<'a> Template {
         }
 
         // then write template files which can override files from base
-        for file in FRAGMENTS::iter().filter(|e| {
+        for file in source.iter().filter(|e| {
             path::PathBuf::from(e.to_string())
                 .components()
                 .next()
@@ -346,9 +655,9 @@ impl<'a> Template {
 
         // then write extra files specified in the fragment manifest
         for (src, dest) in manifest.files {
-            let data = FRAGMENTS::get(&format!("_assets_/{src}"))
-                .with_context(|| format!("Failed to get asset file bytes: {src}"))?
-                .data;
+            let data = source
+                .get(&format!("_assets_/{src}"))
+                .with_context(|| format!("Failed to get asset file bytes: {src}"))?;
             let dest = target_dir.join(dest);
             let parent = dest.parent().unwrap();
             fs::create_dir_all(parent)?;
@@ -359,6 +668,19 @@ impl<'a> Template {
             file.write_all(&data)?;
         }
 
+        let config = GenerationConfig::for_render(
+            *self,
+            pkg_manager,
+            package_name,
+            alpha,
+            mobile,
+            source.kind(),
+        );
+        fs::write(
+            target_dir.join(GenerationConfig::FILE_NAME),
+            config.to_json()?,
+        )?;
+
         Ok(())
     }
 
@@ -369,8 +691,10 @@ impl<'a> Template {
         pkg_manager: PackageManager,
         manifest: Manifest,
     ) -> String {
+        // Replacement order is important
+        let content = Self::replace_dep_versions(content, &manifest);
         manifest
-            .replace_vars(content)
+            .replace_vars(&content)
             .replace("~lib_name~", lib_name)
             .replace("~package_name~", package_name)
             .replace("~pkg_manager_run_command~", pkg_manager.run_cmd())
@@ -383,6 +707,41 @@ impl<'a> Template {
                 },
             )
     }
+
+    /// Replaces `~dep:<name>~` tokens with the version pinned for `<name>` in the fragment
+    /// manifest's `[dependencies]`/`[versions]` section, so every rendered `Cargo.toml` and
+    /// `package.json` stays in lockstep with a single authoritative version map.
+    fn replace_dep_versions(content: &str, manifest: &Manifest) -> String {
+        let mut result = String::with_capacity(content.len());
+        let mut rest = content;
+
+        while let Some(start) = rest.find("~dep:") {
+            result.push_str(&rest[..start]);
+            let after = &rest[start + "~dep:".len()..];
+
+            let Some(end) = after.find('~') else {
+                result.push_str("~dep:");
+                rest = after;
+                break;
+            };
+
+            let name = &after[..end];
+            match manifest.dependency_version(name) {
+                Some(version) => result.push_str(version),
+                // no pinned version for this dependency: leave the token as-is so it's obvious
+                // something's missing instead of silently rendering an empty string
+                None => {
+                    result.push_str("~dep:");
+                    result.push_str(name);
+                    result.push('~');
+                }
+            }
+            rest = &after[end + 1..];
+        }
+
+        result.push_str(rest);
+        result
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -401,10 +760,234 @@ impl Display for Flavor {
     }
 }
 
+impl FromStr for Flavor {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "JavaScript" => Ok(Flavor::JavaScript),
+            "TypeScript" => Ok(Flavor::TypeScript),
+            _ => Err(format!(
+                "{YELLOW}{s}{RESET} is not a valid flavor.{}",
+                suggestion_suffix(s, &[Flavor::JavaScript, Flavor::TypeScript])
+            )),
+        }
+    }
+}
+
+/// Every decision made while scaffolding a project: the selected [`Template`], [`Flavor`],
+/// [`PackageManager`], `alpha`/`mobile` flags and package name. Written to
+/// [`GenerationConfig::FILE_NAME`] in the generated project by [`Template::render`], and read
+/// back by [`GenerationConfig::generate`] to reproduce the exact same project non-interactively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerationConfig {
+    pub template: Template,
+    pub flavor: Option<Flavor>,
+    pub pkg_manager: PackageManager,
+    pub package_name: String,
+    pub alpha: bool,
+    pub mobile: bool,
+    pub source: TemplateSourceKind,
+}
+
+impl GenerationConfig {
+    /// Name of the config file written into (and read back from) generated projects.
+    pub const FILE_NAME: &'static str = "create-tauri-app.json";
+
+    fn for_render(
+        template: Template,
+        pkg_manager: PackageManager,
+        package_name: &str,
+        alpha: bool,
+        mobile: bool,
+        source: TemplateSourceKind,
+    ) -> Self {
+        let flavor = if template.without_flavor() != template {
+            Some(Flavor::TypeScript)
+        } else if template.flavors(pkg_manager).is_some() {
+            Some(Flavor::JavaScript)
+        } else {
+            None
+        };
+        Self {
+            template,
+            flavor,
+            pkg_manager,
+            package_name: package_name.to_string(),
+            alpha,
+            mobile,
+            source,
+        }
+    }
+
+    /// Serializes this configuration to pretty-printed JSON. Object keys are sorted
+    /// alphabetically (the default for [`serde_json::Value`]), so two equal configs always
+    /// serialize byte-identically.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        let source = match &self.source {
+            TemplateSourceKind::Embedded => serde_json::json!({ "kind": "embedded" }),
+            TemplateSourceKind::Directory(dir) => serde_json::json!({
+                "kind": "directory",
+                "location": dir.to_string_lossy(),
+            }),
+            TemplateSourceKind::Git(url) => serde_json::json!({
+                "kind": "git",
+                "location": url,
+            }),
+            TemplateSourceKind::Tarball(url) => serde_json::json!({
+                "kind": "tarball",
+                "location": url,
+            }),
+        };
+
+        let value = serde_json::json!({
+            "alpha": self.alpha,
+            "flavor": self.flavor.map(|f| f.to_string()),
+            "mobile": self.mobile,
+            "packageManager": self.pkg_manager.to_string(),
+            "packageName": self.package_name,
+            "source": source,
+            "template": self.template.to_string(),
+        });
+        serde_json::to_string_pretty(&value).with_context(|| "failed to serialize config")
+    }
+
+    fn from_json(content: &str) -> anyhow::Result<Self> {
+        let value: serde_json::Value =
+            serde_json::from_str(content).with_context(|| "config is not valid JSON")?;
+
+        let field = |key: &str| -> anyhow::Result<&str> {
+            value[key]
+                .as_str()
+                .with_context(|| format!("config is missing `{key}`"))
+        };
+
+        let template = field("template")?
+            .parse::<Template>()
+            .map_err(|e| anyhow::anyhow!(e))?;
+        let flavor = value["flavor"]
+            .as_str()
+            .map(|f| f.parse::<Flavor>().map_err(|e| anyhow::anyhow!(e)))
+            .transpose()?;
+        let pkg_manager = field("packageManager")?
+            .parse::<PackageManager>()
+            .map_err(|e| anyhow::anyhow!(e))?;
+        let package_name = field("packageName")?.to_string();
+        let alpha = value["alpha"].as_bool().unwrap_or(false);
+        let mobile = value["mobile"].as_bool().unwrap_or(false);
+
+        let source_value = &value["source"];
+        let source_kind = source_value["kind"]
+            .as_str()
+            .with_context(|| "config is missing `source.kind`")?;
+        let source_location = || -> anyhow::Result<String> {
+            source_value["location"]
+                .as_str()
+                .with_context(|| "config is missing `source.location`")
+                .map(str::to_string)
+        };
+        let source = match source_kind {
+            "embedded" => TemplateSourceKind::Embedded,
+            "directory" => TemplateSourceKind::Directory(path::PathBuf::from(source_location()?)),
+            "git" => TemplateSourceKind::Git(source_location()?),
+            "tarball" => TemplateSourceKind::Tarball(source_location()?),
+            other => anyhow::bail!("config has unknown `source.kind`: {other}"),
+        };
+
+        Ok(Self {
+            template,
+            flavor,
+            pkg_manager,
+            package_name,
+            alpha,
+            mobile,
+            source,
+        })
+    }
+
+    /// Reads a config file previously written by [`Template::render`] and regenerates the exact
+    /// same project from it, skipping all interactive prompts. Rebuilds whichever
+    /// [`TemplateSource`] (embedded templates, `--template-dir`, or `--template-git`) produced
+    /// the original project, so regeneration never silently falls back to the embedded templates.
+    pub fn generate(path: &path::Path, target_dir: &path::Path) -> anyhow::Result<()> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read config file {}", path.display()))?;
+        let config = Self::from_json(&content)?;
+
+        let source: Box<dyn TemplateSource> = match &config.source {
+            TemplateSourceKind::Embedded => Box::new(EmbeddedSource),
+            TemplateSourceKind::Directory(dir) => Box::new(FilesystemSource::new(dir.clone())),
+            TemplateSourceKind::Git(url) => Box::new(RemoteSource::from_git(url)?),
+            TemplateSourceKind::Tarball(url) => Box::new(RemoteSource::from_tarball(url)?),
+        };
+
+        config.template.render_from_source(
+            source.as_ref(),
+            target_dir,
+            config.pkg_manager,
+            &config.package_name,
+            config.alpha,
+            config.mobile,
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn suggests_closest_template_on_typo() {
+        let err = Template::from_str("tuari-ts").unwrap_err();
+        assert!(err.contains(&format!("Did you mean `{GREEN}vue-ts{RESET}`?")), "{err}");
+
+        let err = Template::from_str("reacts").unwrap_err();
+        assert!(err.contains(&format!("Did you mean `{GREEN}react{RESET}`?")), "{err}");
+
+        // too far from anything to be a useful suggestion
+        let err = Template::from_str("xxxxxxxxxxxx").unwrap_err();
+        assert!(!err.contains("Did you mean"), "{err}");
+    }
+
+    #[test]
+    fn suggests_closest_flavor_on_typo() {
+        let err = Flavor::from_str("Typescript").unwrap_err();
+        assert!(err.contains(&format!("Did you mean `{GREEN}TypeScript{RESET}`?")), "{err}");
+    }
+
+    #[test]
+    fn generation_config_round_trips() {
+        let config = GenerationConfig::for_render(
+            Template::VueTs,
+            PackageManager::Pnpm,
+            "cta-app",
+            false,
+            false,
+            TemplateSourceKind::Embedded,
+        );
+
+        let json = config.to_json().unwrap();
+        let parsed = GenerationConfig::from_json(&json).unwrap();
+        assert_eq!(config, parsed);
+        assert_eq!(json, parsed.to_json().unwrap());
+    }
+
+    #[test]
+    fn generation_config_round_trips_non_embedded_source() {
+        let config = GenerationConfig::for_render(
+            Template::React,
+            PackageManager::Npm,
+            "cta-app",
+            false,
+            false,
+            TemplateSourceKind::Git("https://example.com/custom-templates.git".to_string()),
+        );
+
+        let json = config.to_json().unwrap();
+        let parsed = GenerationConfig::from_json(&json).unwrap();
+        assert_eq!(config, parsed);
+        assert_eq!(json, parsed.to_json().unwrap());
+    }
+
     #[test]
     fn it_works() {
         let manifest_file = r#"
@@ -419,6 +1002,9 @@ mod test {
         [files]
         tauri.svg = src/assets/tauri.svg
         styles.css = src/styles.css
+
+        [versions]
+        tauri = 2
     "#;
 
         let content = r#"{
@@ -428,6 +1014,9 @@ mod test {
         "devPath": "~fragment_dev_path~",
         "distDir": "~fragment_dist_dir~"
     },
+    "dependencies": {
+        "tauri": "~dep:tauri~"
+    },
 }"#;
 
         let manifest = Manifest::parse(manifest_file, false).unwrap();
@@ -441,6 +1030,9 @@ mod test {
         "devPath": "http://localhost:1420",
         "distDir": ""
     },
+    "dependencies": {
+        "tauri": "2"
+    },
 }"#
             .to_string()
         );
@@ -462,8 +1054,181 @@ mod test {
         "devPath": "http://localhost:1420",
         "distDir": ""
     },
+    "dependencies": {
+        "tauri": "2"
+    },
 }"#
             .to_string()
         );
     }
+
+    #[test]
+    fn replace_dep_versions_leaves_unknown_tokens_untouched() {
+        let manifest = Manifest::parse(
+            r#"
+        devPath = http://localhost:1420
+
+        [versions]
+        tauri = 2
+    "#,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Template::replace_dep_versions("~dep:tauri~ / ~dep:missing~", &manifest),
+            "2 / ~dep:missing~"
+        );
+    }
+
+    #[test]
+    fn resolve_conditional_file_name_matches_pkg_manager_and_stage() {
+        // no flags: always written
+        assert_eq!(
+            Template::resolve_conditional_file_name(
+                "%()%package.json",
+                PackageManager::Npm,
+                false,
+                false,
+                "linux"
+            ),
+            Some("package.json")
+        );
+
+        // pkg manager flags are OR-combined, unmatched managers are skipped
+        assert_eq!(
+            Template::resolve_conditional_file_name(
+                "%(pnpm-yarn)%package.json",
+                PackageManager::Npm,
+                false,
+                false,
+                "linux"
+            ),
+            None
+        );
+        assert_eq!(
+            Template::resolve_conditional_file_name(
+                "%(pnpm-yarn)%package.json",
+                PackageManager::Pnpm,
+                false,
+                false,
+                "linux"
+            ),
+            Some("package.json")
+        );
+
+        // stable/alpha/mobile gate on the release stage
+        assert_eq!(
+            Template::resolve_conditional_file_name(
+                "%(alpha)%Cargo.toml",
+                PackageManager::Cargo,
+                false,
+                false,
+                "linux"
+            ),
+            None
+        );
+        assert_eq!(
+            Template::resolve_conditional_file_name(
+                "%(alpha)%Cargo.toml",
+                PackageManager::Cargo,
+                true,
+                false,
+                "linux"
+            ),
+            Some("Cargo.toml")
+        );
+    }
+
+    #[test]
+    fn resolve_conditional_file_name_matches_target_os() {
+        assert_eq!(
+            Template::resolve_conditional_file_name(
+                "%(windows-macos)%main.rs",
+                PackageManager::Cargo,
+                false,
+                false,
+                "linux"
+            ),
+            None
+        );
+        assert_eq!(
+            Template::resolve_conditional_file_name(
+                "%(windows-macos)%main.rs",
+                PackageManager::Cargo,
+                false,
+                false,
+                "macos"
+            ),
+            Some("main.rs")
+        );
+
+        // no OS flags: every host matches
+        assert_eq!(
+            Template::resolve_conditional_file_name(
+                "%(cargo)%main.rs",
+                PackageManager::Cargo,
+                false,
+                false,
+                "linux"
+            ),
+            Some("main.rs")
+        );
+    }
+
+    fn temp_dir(name: &str) -> path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("cta-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn filesystem_source_reads_and_lists_files() {
+        let dir = temp_dir("filesystem-source");
+        fs::create_dir_all(dir.join("_base_/src-tauri")).unwrap();
+        fs::write(dir.join("_base_/src-tauri/Cargo.toml"), b"[package]").unwrap();
+        fs::write(dir.join("README.md"), b"hello").unwrap();
+
+        let source = FilesystemSource::new(&dir);
+
+        assert_eq!(
+            source.get("_base_/src-tauri/Cargo.toml").as_deref(),
+            Some(&b"[package]"[..])
+        );
+        assert_eq!(source.get("missing"), None);
+
+        let mut files = source.iter().collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(files, vec!["README.md", "_base_/src-tauri/Cargo.toml"]);
+
+        assert_eq!(source.kind(), TemplateSourceKind::Directory(dir.clone()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn remote_source_delegates_to_its_inner_filesystem_source() {
+        let dir = temp_dir("remote-source");
+        fs::create_dir_all(dir.join("fragment-vue")).unwrap();
+        fs::write(dir.join("fragment-vue/_cta_manifest_"), b"devPath = /").unwrap();
+
+        let kind = TemplateSourceKind::Git("https://example.com/custom-templates.git".to_string());
+        let source = RemoteSource {
+            inner: FilesystemSource::new(&dir),
+            kind: kind.clone(),
+        };
+
+        assert_eq!(
+            source.get("fragment-vue/_cta_manifest_").as_deref(),
+            Some(&b"devPath = /"[..])
+        );
+        assert_eq!(
+            source.iter().collect::<Vec<_>>(),
+            vec!["fragment-vue/_cta_manifest_".to_string()]
+        );
+        assert_eq!(source.kind(), kind);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }